@@ -0,0 +1,37 @@
+//! Pluggable backends for sending a [`MessagesRequestBody`] to a provider
+//! that hosts Claude.
+//!
+//! The Messages API types (`MessagesRequestBody`, `MessagesResponseBody`,
+//! `StreamChunk`, ...) are shared by every backend; only how a request is
+//! serialized, authenticated and POSTed differs. [`AnthropicBackend`] talks
+//! to the direct Anthropic API; [`BedrockBackend`] talks to a Claude model
+//! hosted on AWS Bedrock.
+
+mod anthropic_backend;
+mod bedrock_backend;
+mod event_stream;
+mod sigv4;
+
+pub use anthropic_backend::AnthropicBackend;
+pub use bedrock_backend::{BedrockBackend, BedrockError};
+
+use crate::messages::{MessagesRequestBody, MessagesResponseBody};
+
+/// Abstracts how a [`MessagesRequestBody`] is serialized, authenticated and
+/// sent to a provider hosting Claude.
+///
+/// Implementations only need to get a [`MessagesResponseBody`] back out;
+/// callers that only depend on `Backend` are portable across every provider
+/// that implements it.
+#[async_trait::async_trait]
+pub trait Backend: Send + Sync {
+    /// The error this backend's requests can fail with.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Sends a single, non-streaming request and returns the completed
+    /// response.
+    async fn send_message(
+        &self,
+        request: MessagesRequestBody,
+    ) -> Result<MessagesResponseBody, Self::Error>;
+}
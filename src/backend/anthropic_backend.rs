@@ -0,0 +1,33 @@
+use crate::backend::Backend;
+use crate::client::Client;
+use crate::messages::{MessagesError, MessagesRequestBody, MessagesResponseBody};
+
+/// The default [`Backend`]: sends requests straight to the direct
+/// Anthropic API.
+///
+/// This simply delegates to the existing [`Client`]; it exists so that
+/// code written against the [`Backend`] trait also covers the direct API
+/// without a special case.
+#[derive(Debug, Clone)]
+pub struct AnthropicBackend {
+    client: Client,
+}
+
+impl AnthropicBackend {
+    /// Creates a backend that sends requests through `client`.
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for AnthropicBackend {
+    type Error = MessagesError;
+
+    async fn send_message(
+        &self,
+        request: MessagesRequestBody,
+    ) -> Result<MessagesResponseBody, Self::Error> {
+        crate::messages::api::create_a_message(&self.client, request).await
+    }
+}
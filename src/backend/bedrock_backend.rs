@@ -0,0 +1,397 @@
+use crate::backend::event_stream;
+use crate::backend::sigv4::{self, AwsCredentials};
+use crate::backend::Backend;
+use crate::messages::{
+    ClaudeModel, Message, MessagesRequestBody, MessagesResponseBody, Metadata,
+    StopSequence, StreamChunk, SystemPrompt, Temperature, Tool, ToolChoice,
+    TopK, TopP,
+};
+use base64::Engine as _;
+use std::fmt::{Display, Formatter};
+
+const BEDROCK_ANTHROPIC_VERSION: &str = "bedrock-2023-05-31";
+
+/// A [`Backend`] that sends requests to a Claude model hosted on
+/// [AWS Bedrock](https://docs.aws.amazon.com/bedrock/latest/userguide/model-parameters-anthropic-claude-messages.html),
+/// signing them with AWS Signature Version 4.
+#[derive(Clone)]
+pub struct BedrockBackend {
+    http_client: reqwest::Client,
+    credentials: AwsCredentials,
+    region: String,
+}
+
+impl BedrockBackend {
+    /// Creates a backend that invokes Bedrock models in `region` using the
+    /// given long-term or temporary (STS) credentials.
+    pub fn new<S>(
+        access_key_id: S,
+        secret_access_key: S,
+        session_token: Option<String>,
+        region: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            http_client: reqwest::Client::new(),
+            credentials: AwsCredentials {
+                access_key_id: access_key_id.into(),
+                secret_access_key: secret_access_key.into(),
+                session_token,
+            },
+            region: region.into(),
+        }
+    }
+
+    fn endpoint(
+        &self,
+        model: ClaudeModel,
+        stream: bool,
+    ) -> (String, String) {
+        let action = if stream {
+            "invoke-with-response-stream"
+        } else {
+            "invoke"
+        };
+        let uri_path = format!(
+            "/model/{}/{}",
+            urlencode_model_id(model.bedrock_model_id()),
+            action
+        );
+        let url = format!(
+            "https://bedrock-runtime.{}.amazonaws.com{}",
+            self.region, uri_path
+        );
+        (url, uri_path)
+    }
+
+    fn signed_headers(
+        &self,
+        uri_path: &str,
+        body: &[u8],
+    ) -> Vec<(String, String)> {
+        let timestamp = time::OffsetDateTime::now_utc();
+        let amz_date = sigv4::format_amz_date(timestamp);
+        let host = format!("bedrock-runtime.{}.amazonaws.com", self.region);
+
+        let mut headers = vec![
+            ("host".to_string(), host),
+            ("x-amz-date".to_string(), amz_date.clone()),
+        ];
+        if let Some(session_token) = &self.credentials.session_token {
+            headers.push((
+                "x-amz-security-token".to_string(),
+                session_token.clone(),
+            ));
+        }
+
+        let header_refs: Vec<(&str, &str)> = headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+
+        let authorization = sigv4::sign(
+            &self.credentials,
+            &self.region,
+            "bedrock",
+            timestamp,
+            "POST",
+            uri_path,
+            &header_refs,
+            body,
+        );
+        headers.push(("authorization".to_string(), authorization));
+        headers
+    }
+
+    /// Invokes a Bedrock model through its streaming endpoint, decoding each
+    /// event-stream frame into a [`StreamChunk`] as it arrives over the
+    /// network.
+    ///
+    /// The chunks are collected and returned together, in order, once the
+    /// stream has ended; none is handed to the caller before then.
+    ///
+    /// Bedrock wraps every frame's payload as `{"bytes": "<base64>"}`; once
+    /// base64-decoded, `bytes` is the same JSON shape the direct Anthropic
+    /// API's SSE `data:` events carry, so the result is portable across
+    /// providers via
+    /// [`MessageAccumulator`](crate::messages::MessageAccumulator).
+    pub async fn stream_message(
+        &self,
+        request: MessagesRequestBody,
+    ) -> Result<Vec<StreamChunk>, BedrockError> {
+        let (url, uri_path) = self.endpoint(request.model, true);
+        let body = serde_json::to_vec(&BedrockRequestBody::from(request))
+            .map_err(BedrockError::Serialize)?;
+        let headers = self.signed_headers(&uri_path, &body);
+
+        let mut request_builder = self.http_client.post(url).body(body);
+        for (name, value) in headers {
+            request_builder = request_builder.header(name, value);
+        }
+
+        let mut response = request_builder
+            .send()
+            .await
+            .map_err(BedrockError::Request)?;
+        ensure_success(response.status(), &response)?;
+
+        let mut buffer = Vec::new();
+        let mut chunks = Vec::new();
+        while let Some(bytes) = response
+            .chunk()
+            .await
+            .map_err(BedrockError::Request)?
+        {
+            buffer.extend_from_slice(&bytes);
+            let consumed = {
+                let (payloads, remainder) =
+                    event_stream::decode_messages(&buffer);
+                let consumed = buffer.len() - remainder.len();
+                for payload in payloads {
+                    chunks.push(decode_bedrock_event(&payload)?);
+                }
+                consumed
+            };
+            buffer.drain(..consumed);
+        }
+
+        Ok(chunks)
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for BedrockBackend {
+    type Error = BedrockError;
+
+    async fn send_message(
+        &self,
+        request: MessagesRequestBody,
+    ) -> Result<MessagesResponseBody, Self::Error> {
+        let (url, uri_path) = self.endpoint(request.model, false);
+        let body = serde_json::to_vec(&BedrockRequestBody::from(request))
+            .map_err(BedrockError::Serialize)?;
+        let headers = self.signed_headers(&uri_path, &body);
+
+        let mut request_builder = self.http_client.post(url).body(body);
+        for (name, value) in headers {
+            request_builder = request_builder.header(name, value);
+        }
+
+        let response = request_builder
+            .send()
+            .await
+            .map_err(BedrockError::Request)?;
+        let status = response.status();
+        ensure_success(status, &response)?;
+
+        response
+            .json::<MessagesResponseBody>()
+            .await
+            .map_err(BedrockError::Request)
+    }
+}
+
+fn ensure_success(
+    status: reqwest::StatusCode,
+    _response: &reqwest::Response,
+) -> Result<(), BedrockError> {
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(BedrockError::Status(status))
+    }
+}
+
+fn urlencode_model_id(model_id: &str) -> String {
+    // The only reserved character a Bedrock model ID contains is `:`.
+    model_id.replace(':', "%3A")
+}
+
+/// The wrapper Bedrock puts around each event-stream frame's payload.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct BedrockEventEnvelope {
+    bytes: String,
+}
+
+/// Unwraps a single decoded event-stream frame into the [`StreamChunk`] it
+/// carries.
+fn decode_bedrock_event(
+    payload: &[u8]
+) -> Result<StreamChunk, BedrockError> {
+    let envelope: BedrockEventEnvelope = serde_json::from_slice(payload)
+        .map_err(BedrockError::Deserialize)?;
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(envelope.bytes)
+        .map_err(BedrockError::Base64)?;
+    serde_json::from_slice(&decoded).map_err(BedrockError::Deserialize)
+}
+
+/// The wire shape of a Bedrock `InvokeModel` request body for an Anthropic
+/// model: the same fields as [`MessagesRequestBody`], minus `model` (which
+/// is part of the URL instead) and `stream` (Bedrock selects streaming via
+/// the endpoint path instead of a body field), and with `anthropic_version`
+/// added.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct BedrockRequestBody {
+    anthropic_version: &'static str,
+    messages: Vec<Message>,
+    max_tokens: crate::messages::MaxTokens,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<Vec<StopSequence>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<SystemPrompt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<Temperature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<Tool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<ToolChoice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_k: Option<TopK>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<TopP>,
+}
+
+impl From<MessagesRequestBody> for BedrockRequestBody {
+    fn from(request: MessagesRequestBody) -> Self {
+        Self {
+            anthropic_version: BEDROCK_ANTHROPIC_VERSION,
+            messages: request.messages,
+            max_tokens: request.max_tokens,
+            metadata: request.metadata,
+            stop_sequences: request.stop_sequences,
+            system: request.system,
+            temperature: request.temperature,
+            tools: request.tools,
+            tool_choice: request.tool_choice,
+            top_k: request.top_k,
+            top_p: request.top_p,
+        }
+    }
+}
+
+/// An error returned by [`BedrockBackend`].
+#[derive(Debug)]
+pub enum BedrockError {
+    /// The request could not be serialized.
+    Serialize(serde_json::Error),
+    /// An event-stream frame of a streamed response could not be
+    /// deserialized.
+    Deserialize(serde_json::Error),
+    /// An event-stream frame's `bytes` field was not valid base64.
+    Base64(base64::DecodeError),
+    /// The underlying HTTP request failed, or a non-streaming response body
+    /// could not be decoded.
+    Request(reqwest::Error),
+    /// Bedrock returned a non-success status code.
+    Status(reqwest::StatusCode),
+}
+
+impl Display for BedrockError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | BedrockError::Serialize(source) => {
+                write!(f, "failed to serialize Bedrock request: {}", source)
+            },
+            | BedrockError::Deserialize(source) => {
+                write!(f, "failed to deserialize Bedrock response: {}", source)
+            },
+            | BedrockError::Base64(source) => {
+                write!(f, "failed to decode Bedrock event bytes: {}", source)
+            },
+            | BedrockError::Request(source) => {
+                write!(f, "Bedrock request failed: {}", source)
+            },
+            | BedrockError::Status(status) => {
+                write!(f, "Bedrock returned status {}", status)
+            },
+        }
+    }
+}
+
+impl std::error::Error for BedrockError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            | BedrockError::Serialize(source) => Some(source),
+            | BedrockError::Deserialize(source) => Some(source),
+            | BedrockError::Base64(source) => Some(source),
+            | BedrockError::Request(source) => Some(source),
+            | BedrockError::Status(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{MaxTokens, Role};
+
+    #[test]
+    fn bedrock_request_body_omits_model() {
+        let request = MessagesRequestBody::new(
+            ClaudeModel::Claude3Sonnet20240229,
+            vec![Message {
+                role: Role::User,
+                content: "hello".into(),
+            }],
+            MaxTokens::new(1024),
+        );
+        let body = BedrockRequestBody::from(request);
+        let value = serde_json::to_value(&body).unwrap();
+        assert_eq!(
+            value.get("anthropic_version").unwrap(),
+            "bedrock-2023-05-31"
+        );
+        assert!(value.get("model").is_none());
+        assert!(value.get("stream").is_none());
+    }
+
+    #[test]
+    fn decode_bedrock_event_unwraps_base64_bytes() {
+        let payload = base64::engine::general_purpose::STANDARD
+            .encode("{\"type\":\"ping\"}");
+        let envelope =
+            format!("{{\"bytes\":\"{}\"}}", payload);
+
+        let chunk = decode_bedrock_event(envelope.as_bytes()).unwrap();
+
+        assert_eq!(
+            chunk,
+            StreamChunk::Ping(crate::messages::PingChunk {
+                _type: crate::messages::StreamChunkType::Ping,
+            })
+        );
+    }
+
+    #[test]
+    fn urlencode_model_id_escapes_colon() {
+        assert_eq!(
+            urlencode_model_id("anthropic.claude-3-sonnet-20240229-v1:0"),
+            "anthropic.claude-3-sonnet-20240229-v1%3A0"
+        );
+    }
+
+    #[test]
+    fn endpoint_builds_expected_path() {
+        let backend = BedrockBackend::new(
+            "AKIDEXAMPLE",
+            "secret",
+            None,
+            "us-east-1",
+        );
+        let (url, uri_path) =
+            backend.endpoint(ClaudeModel::Claude3Haiku20240307, false);
+        assert_eq!(
+            uri_path,
+            "/model/anthropic.claude-3-haiku-20240307-v1%3A0/invoke"
+        );
+        assert!(url.starts_with("https://bedrock-runtime.us-east-1.amazonaws.com"));
+    }
+}
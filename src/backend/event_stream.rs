@@ -0,0 +1,81 @@
+//! A minimal decoder for the `application/vnd.amazon.eventstream` framing
+//! AWS Bedrock uses for `InvokeModelWithResponseStream` responses.
+//!
+//! Each message is `total_length:u32 | headers_length:u32 | prelude_crc:u32
+//! | headers | payload | message_crc:u32`, all big-endian. We only need the
+//! payload of each message (the CRCs protect against transport corruption,
+//! which TLS already rules out here, so we don't recompute them).
+
+const PRELUDE_LEN: usize = 8;
+
+/// Splits `buffer` into the payloads of every complete event-stream
+/// message it contains, returning those payloads and the unconsumed
+/// remainder of `buffer` (a partial message, if any, is left for the next
+/// call once more bytes arrive).
+pub(crate) fn decode_messages(buffer: &[u8]) -> (Vec<Vec<u8>>, &[u8]) {
+    let mut payloads = Vec::new();
+    let mut offset = 0;
+
+    while buffer.len() - offset >= PRELUDE_LEN {
+        let total_length = read_u32(&buffer[offset..]) as usize;
+        if buffer.len() - offset < total_length {
+            break;
+        }
+
+        let headers_length =
+            read_u32(&buffer[offset + 4..]) as usize;
+        let payload_start = offset + PRELUDE_LEN + 4 + headers_length;
+        let payload_end = offset + total_length - 4; // exclude message_crc
+
+        if payload_end >= payload_start {
+            payloads.push(buffer[payload_start..payload_end].to_vec());
+        }
+
+        offset += total_length;
+    }
+
+    (payloads, &buffer[offset..])
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_message(payload: &[u8]) -> Vec<u8> {
+        let headers_length: u32 = 0;
+        let total_length =
+            (PRELUDE_LEN + 4 + headers_length as usize + payload.len() + 4)
+                as u32;
+
+        let mut message = Vec::new();
+        message.extend_from_slice(&total_length.to_be_bytes());
+        message.extend_from_slice(&headers_length.to_be_bytes());
+        message.extend_from_slice(&0u32.to_be_bytes()); // prelude_crc (unchecked)
+        message.extend_from_slice(payload);
+        message.extend_from_slice(&0u32.to_be_bytes()); // message_crc (unchecked)
+        message
+    }
+
+    #[test]
+    fn decodes_a_single_complete_message() {
+        let message = encode_message(b"{\"hello\":true}");
+        let (payloads, remainder) = decode_messages(&message);
+        assert_eq!(payloads, vec![b"{\"hello\":true}".to_vec()]);
+        assert!(remainder.is_empty());
+    }
+
+    #[test]
+    fn decodes_multiple_messages_and_keeps_the_partial_tail() {
+        let mut buffer = encode_message(b"one");
+        buffer.extend_from_slice(&encode_message(b"two"));
+        buffer.extend_from_slice(&[0, 0, 0]); // a partial next message
+
+        let (payloads, remainder) = decode_messages(&buffer);
+        assert_eq!(payloads, vec![b"one".to_vec(), b"two".to_vec()]);
+        assert_eq!(remainder, &[0, 0, 0]);
+    }
+}
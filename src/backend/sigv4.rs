@@ -0,0 +1,188 @@
+//! A minimal AWS Signature Version 4 signer, just enough to sign a
+//! `bedrock-runtime` `InvokeModel`/`InvokeModelWithResponseStream` POST
+//! request.
+//!
+//! See also [Signing AWS requests with Signature Version 4](https://docs.aws.amazon.com/IAM/latest/UserGuide/create-signed-request.html).
+
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// AWS credentials used to sign a request.
+#[derive(Clone)]
+pub(crate) struct AwsCredentials {
+    pub(crate) access_key_id: String,
+    pub(crate) secret_access_key: String,
+    /// Present when using temporary (STS) credentials.
+    pub(crate) session_token: Option<String>,
+}
+
+/// Computes the `Authorization` header value for a signed request to
+/// `service` in `region` at `timestamp`, signing every header in `headers`.
+///
+/// Only the `Authorization` value is returned; when using temporary
+/// credentials the caller adds `x-amz-security-token` to `headers` itself.
+pub(crate) fn sign(
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    timestamp: time::OffsetDateTime,
+    method: &str,
+    uri_path: &str,
+    headers: &[(&str, &str)],
+    payload: &[u8],
+) -> String {
+    let amz_date = format_amz_date(timestamp);
+    let date_stamp = &amz_date[..8];
+
+    let mut sorted_headers = headers.to_vec();
+    sorted_headers.sort_by_key(|(name, _)| name.to_lowercase());
+
+    let canonical_headers: String = sorted_headers
+        .iter()
+        .map(|(name, value)| {
+            format!("{}:{}\n", name.to_lowercase(), value.trim())
+        })
+        .collect();
+    let signed_headers = sorted_headers
+        .iter()
+        .map(|(name, _)| name.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{method}\n{uri_path}\n\n{canonical_headers}\n{signed_headers}\n{payload_hash}",
+        method = method,
+        uri_path = uri_path,
+        canonical_headers = canonical_headers,
+        signed_headers = signed_headers,
+        payload_hash = hex_sha256(payload),
+    );
+
+    let credential_scope =
+        format!("{date_stamp}/{region}/{service}/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{credential_scope}\n{hashed_canonical_request}",
+        amz_date = amz_date,
+        credential_scope = credential_scope,
+        hashed_canonical_request = hex_sha256(canonical_request.as_bytes()),
+    );
+
+    let signing_key = derive_signing_key(
+        &credentials.secret_access_key,
+        date_stamp,
+        region,
+        service,
+    );
+    let signature = hex_hmac(&signing_key, string_to_sign.as_bytes());
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={access_key_id}/{credential_scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        access_key_id = credentials.access_key_id,
+        credential_scope = credential_scope,
+        signed_headers = signed_headers,
+        signature = signature,
+    )
+}
+
+/// Formats a timestamp as `YYYYMMDDTHHMMSSZ`, the format SigV4 requires for
+/// the `X-Amz-Date` header and credential scope.
+pub(crate) fn format_amz_date(timestamp: time::OffsetDateTime) -> String {
+    let timestamp = timestamp.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        timestamp.year(),
+        u8::from(timestamp.month()),
+        timestamp.day(),
+        timestamp.hour(),
+        timestamp.minute(),
+        timestamp.second(),
+    )
+}
+
+fn derive_signing_key(
+    secret_access_key: &str,
+    date_stamp: &str,
+    region: &str,
+    service: &str,
+) -> Vec<u8> {
+    let k_date = hmac(
+        format!("AWS4{secret_access_key}").as_bytes(),
+        date_stamp.as_bytes(),
+    );
+    let k_region = hmac(&k_date, region.as_bytes());
+    let k_service = hmac(&k_region, service.as_bytes());
+    hmac(&k_service, b"aws4_request")
+}
+
+fn hmac(
+    key: &[u8],
+    data: &[u8],
+) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key)
+        .expect("HMAC accepts a key of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex_hmac(
+    key: &[u8],
+    data: &[u8],
+) -> String {
+    hex::encode(hmac(key, data))
+}
+
+fn hex_sha256(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_amz_date_pads_single_digit_fields() {
+        let timestamp = time::OffsetDateTime::from_unix_timestamp(0)
+            .unwrap();
+        assert_eq!(format_amz_date(timestamp), "19700101T000000Z");
+    }
+
+    #[test]
+    fn sign_is_deterministic_for_the_same_inputs() {
+        let credentials = AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "secret".to_string(),
+            session_token: None,
+        };
+        let timestamp = time::OffsetDateTime::from_unix_timestamp(0)
+            .unwrap();
+        let headers = [("host", "bedrock-runtime.us-east-1.amazonaws.com")];
+
+        let first = sign(
+            &credentials,
+            "us-east-1",
+            "bedrock",
+            timestamp,
+            "POST",
+            "/model/anthropic.claude-3-sonnet-20240229-v1%3A0/invoke",
+            &headers,
+            b"{}",
+        );
+        let second = sign(
+            &credentials,
+            "us-east-1",
+            "bedrock",
+            timestamp,
+            "POST",
+            "/model/anthropic.claude-3-sonnet-20240229-v1%3A0/invoke",
+            &headers,
+            b"{}",
+        );
+
+        assert_eq!(first, second);
+        assert!(first.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+    }
+}
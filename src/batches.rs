@@ -0,0 +1,26 @@
+//! The [Message Batches API](https://docs.anthropic.com/claude/reference/migrating-to-message-batches) implementations.
+//!
+//! The Message Batches API lets you submit many [`MessagesRequestBody`]
+//! requests in a single call and have Claude process them asynchronously,
+//! without the caller having to manage its own request concurrency.
+//!
+//! [`MessagesRequestBody`]: crate::messages::MessagesRequestBody
+
+mod batch_request;
+mod create_a_message_batch_request_body;
+mod message_batch;
+mod message_batch_individual_response;
+mod processing_status;
+mod result;
+
+pub(crate) mod api;
+
+pub use batch_request::BatchRequest;
+pub use create_a_message_batch_request_body::CreateAMessageBatchRequestBody;
+pub use message_batch::MessageBatch;
+pub use message_batch::MessageBatchObjectType;
+pub use message_batch::RequestCounts;
+pub use message_batch_individual_response::MessageBatchIndividualResponse;
+pub use message_batch_individual_response::MessageBatchResult;
+pub use processing_status::ProcessingStatus;
+pub use result::BatchesResult;
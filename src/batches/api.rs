@@ -0,0 +1,88 @@
+//! The internal request layer for the Message Batches API.
+
+use crate::batches::{
+    BatchesResult, CreateAMessageBatchRequestBody, MessageBatch,
+    MessageBatchIndividualResponse,
+};
+use crate::client::Client;
+use crate::messages::MessagesError;
+
+/// Calls the [create a message batch](https://docs.anthropic.com/claude/reference/migrating-to-message-batches) API.
+pub(crate) async fn create_a_message_batch(
+    client: &Client,
+    request_body: CreateAMessageBatchRequestBody,
+) -> BatchesResult<MessageBatch> {
+    let request = client
+        .post_json("/v1/messages/batches", &request_body)
+        .map_err(MessagesError::from)?;
+
+    let response = request
+        .send()
+        .await
+        .map_err(MessagesError::from)?;
+
+    client
+        .parse_response::<MessageBatch, MessagesError>(response)
+        .await
+}
+
+/// Calls the retrieve a message batch API, polling the current
+/// `processing_status` of a batch created with
+/// [`create_a_message_batch`].
+pub(crate) async fn retrieve_a_message_batch(
+    client: &Client,
+    message_batch_id: &str,
+) -> BatchesResult<MessageBatch> {
+    let request = client
+        .get(&format!("/v1/messages/batches/{message_batch_id}"))
+        .map_err(MessagesError::from)?;
+
+    let response = request
+        .send()
+        .await
+        .map_err(MessagesError::from)?;
+
+    client
+        .parse_response::<MessageBatch, MessagesError>(response)
+        .await
+}
+
+/// Calls the retrieve message batch results API.
+///
+/// Only callable once the batch's `processing_status` is "ended"; results
+/// are returned as one [`MessageBatchIndividualResponse`] per `.jsonl` line.
+pub(crate) async fn retrieve_message_batch_results(
+    client: &Client,
+    message_batch_id: &str,
+) -> BatchesResult<Vec<MessageBatchIndividualResponse>> {
+    let request = client
+        .get(&format!(
+            "/v1/messages/batches/{message_batch_id}/results"
+        ))
+        .map_err(MessagesError::from)?;
+
+    let response = request
+        .send()
+        .await
+        .map_err(MessagesError::from)?;
+
+    if !response.status().is_success() {
+        let error = response
+            .json::<MessagesError>()
+            .await
+            .map_err(MessagesError::from)?;
+        return Err(error);
+    }
+
+    let body = client
+        .response_text(response)
+        .await
+        .map_err(MessagesError::from)?;
+
+    body.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(MessagesError::from)
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use crate::macros::impl_display_for_serialize;
+use crate::messages::MessagesRequestBody;
+
+/// A single request within a message batch.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct BatchRequest {
+    /// A developer-provided identifier for this request, unique within the
+    /// batch.
+    ///
+    /// This identifier is copied back onto the corresponding entry in the
+    /// batch's results, so you can match requests and results without
+    /// relying on ordering.
+    pub custom_id: String,
+    /// The parameters for the underlying Messages API request.
+    pub params: MessagesRequestBody,
+}
+
+impl_display_for_serialize!(BatchRequest);
+
+impl BatchRequest {
+    /// Creates a new batch request with the given `custom_id` and Messages
+    /// API parameters.
+    pub fn new<S>(
+        custom_id: S,
+        params: MessagesRequestBody,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            custom_id: custom_id.into(),
+            params,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{ClaudeModel, MaxTokens, Message, Role};
+
+    #[test]
+    fn new() {
+        let request = BatchRequest::new(
+            "request_01",
+            MessagesRequestBody::new(
+                ClaudeModel::Claude3Sonnet20240229,
+                vec![Message {
+                    role: Role::User,
+                    content: "hello".into(),
+                }],
+                MaxTokens::new(1024),
+            ),
+        );
+        assert_eq!(request.custom_id, "request_01");
+    }
+}
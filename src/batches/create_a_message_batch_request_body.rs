@@ -0,0 +1,44 @@
+use crate::batches::BatchRequest;
+use crate::macros::impl_display_for_serialize;
+
+/// The request body for the [create a message batch](https://docs.anthropic.com/claude/reference/migrating-to-message-batches) API.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct CreateAMessageBatchRequestBody {
+    /// The individual requests to process as part of this batch.
+    pub requests: Vec<BatchRequest>,
+}
+
+impl_display_for_serialize!(CreateAMessageBatchRequestBody);
+
+impl CreateAMessageBatchRequestBody {
+    /// Creates a new batch request body from the given requests.
+    pub fn new(requests: Vec<BatchRequest>) -> Self {
+        Self { requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{ClaudeModel, MaxTokens, Message, MessagesRequestBody, Role};
+
+    #[test]
+    fn new() {
+        let body = CreateAMessageBatchRequestBody::new(vec![
+            BatchRequest::new(
+                "request_01",
+                MessagesRequestBody::new(
+                    ClaudeModel::Claude3Sonnet20240229,
+                    vec![Message {
+                        role: Role::User,
+                        content: "hello".into(),
+                    }],
+                    MaxTokens::new(1024),
+                ),
+            ),
+        ]);
+        assert_eq!(body.requests.len(), 1);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::batches::ProcessingStatus;
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+};
+use std::fmt::{Display, Formatter};
+
+/// The batch object returned by the create and retrieve Message Batches
+/// API calls.
+///
+/// See also [the Message Batches API](https://docs.anthropic.com/claude/reference/migrating-to-message-batches).
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageBatch {
+    /// Unique object identifier.
+    ///
+    /// The format and length of IDs may change over time.
+    pub id: String,
+    /// Object type.
+    ///
+    /// For Message Batches, this is always "message_batch".
+    #[serde(rename = "type")]
+    pub _type: MessageBatchObjectType,
+    /// Processing status of the message batch.
+    pub processing_status: ProcessingStatus,
+    /// Tallies of requests in the message batch, by the status of their
+    /// individual results.
+    pub request_counts: RequestCounts,
+    /// RFC 3339 datetime string representing the time at which the message
+    /// batch was created.
+    pub created_at: String,
+    /// RFC 3339 datetime string representing the time at which processing
+    /// for the message batch ended, if it has ended.
+    pub ended_at: Option<String>,
+    /// RFC 3339 datetime string representing the time at which the message
+    /// batch will expire and be unavailable for retrieval.
+    pub expires_at: String,
+    /// URL from which the results of the message batch can be downloaded,
+    /// once `processing_status` is "ended".
+    pub results_url: Option<String>,
+}
+
+impl_display_for_serialize!(MessageBatch);
+
+/// The object type for a message batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageBatchObjectType {
+    /// message_batch
+    MessageBatch,
+}
+
+impl Display for MessageBatchObjectType {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | MessageBatchObjectType::MessageBatch => {
+                write!(f, "{}", "message_batch")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    MessageBatchObjectType,
+    MessageBatch => "message_batch"
+);
+
+/// Tallies of requests in a message batch, by the status of their
+/// individual results.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct RequestCounts {
+    /// The number of requests still processing.
+    pub processing: u32,
+    /// The number of requests that succeeded.
+    pub succeeded: u32,
+    /// The number of requests that errored.
+    pub errored: u32,
+    /// The number of requests canceled before processing.
+    pub canceled: u32,
+    /// The number of requests that expired before processing.
+    pub expired: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch() -> MessageBatch {
+        MessageBatch {
+            id: "msgbatch_01".to_string(),
+            _type: MessageBatchObjectType::MessageBatch,
+            processing_status: ProcessingStatus::InProgress,
+            request_counts: RequestCounts::default(),
+            created_at: "2024-08-01T00:00:00Z".to_string(),
+            ended_at: None,
+            expires_at: "2024-08-02T00:00:00Z".to_string(),
+            results_url: None,
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&batch()).unwrap(),
+            "{\"id\":\"msgbatch_01\",\"type\":\"message_batch\",\"processing_status\":\"in_progress\",\"request_counts\":{\"processing\":0,\"succeeded\":0,\"errored\":0,\"canceled\":0,\"expired\":0},\"created_at\":\"2024-08-01T00:00:00Z\",\"ended_at\":null,\"expires_at\":\"2024-08-02T00:00:00Z\",\"results_url\":null}"
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<MessageBatch>(
+                &serde_json::to_string(&batch()).unwrap()
+            )
+            .unwrap(),
+            batch()
+        );
+    }
+
+    #[test]
+    fn message_batch_object_type_display() {
+        assert_eq!(
+            MessageBatchObjectType::MessageBatch.to_string(),
+            "message_batch"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::macros::impl_display_for_serialize;
+use crate::messages::{MessagesError, MessagesResponseBody};
+
+/// A single result from the results call, matched back to its request by
+/// `custom_id`.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageBatchIndividualResponse {
+    /// The `custom_id` of the [`BatchRequest`](crate::batches::BatchRequest)
+    /// this result corresponds to.
+    pub custom_id: String,
+    /// The outcome of the request.
+    pub result: MessageBatchResult,
+}
+
+impl_display_for_serialize!(MessageBatchIndividualResponse);
+
+/// The outcome of a single request within a message batch.
+///
+/// Each variant's payload is wrapped under the key the Message Batches
+/// results `.jsonl` actually uses for it, which differs by variant:
+/// `message` for a succeeded result, `error` for an errored one.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageBatchResult {
+    /// The request completed successfully.
+    Succeeded {
+        /// The successful response.
+        message: MessagesResponseBody,
+    },
+    /// The request failed.
+    Errored {
+        /// The error that occurred.
+        error: MessagesError,
+    },
+    /// The request was canceled before it could be processed.
+    Canceled,
+    /// The request expired before it could be processed.
+    Expired,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        ClaudeModel, MessageObjectType, Role, StopReason, Usage,
+    };
+
+    #[test]
+    fn serialize_succeeded() {
+        let response = MessageBatchIndividualResponse {
+            custom_id: "request_01".to_string(),
+            result: MessageBatchResult::Succeeded {
+                message: MessagesResponseBody {
+                    id: "id".to_string(),
+                    _type: MessageObjectType::Message,
+                    role: Role::Assistant,
+                    content: "content".into(),
+                    model: ClaudeModel::Claude3Sonnet20240229,
+                    stop_reason: Some(StopReason::EndTurn),
+                    stop_sequence: None,
+                    usage: Usage {
+                        input_tokens: 1,
+                        output_tokens: 2,
+                    },
+                },
+            },
+        };
+        assert_eq!(response.custom_id, "request_01");
+        let value = serde_json::to_value(&response).unwrap();
+        assert_eq!(value["result"]["type"], "succeeded");
+        assert_eq!(value["result"]["message"]["id"], "id");
+    }
+
+    #[test]
+    fn deserialize_errored() {
+        let response: MessageBatchIndividualResponse = serde_json::from_str(
+            "{\"custom_id\":\"request_03\",\"result\":{\"type\":\"errored\",\"error\":{\"type\":\"error\",\"error\":{\"type\":\"invalid_request_error\",\"message\":\"bad request\"}}}}"
+        )
+        .unwrap();
+        assert_eq!(response.custom_id, "request_03");
+        assert!(matches!(
+            response.result,
+            MessageBatchResult::Errored { .. }
+        ));
+    }
+
+    #[test]
+    fn serialize_canceled() {
+        let response = MessageBatchIndividualResponse {
+            custom_id: "request_02".to_string(),
+            result: MessageBatchResult::Canceled,
+        };
+        assert_eq!(
+            serde_json::to_string(&response).unwrap(),
+            "{\"custom_id\":\"request_02\",\"result\":{\"type\":\"canceled\"}}"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::macros::impl_enum_string_serialization;
+use std::fmt::{Display, Formatter};
+
+/// The processing status of a message batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessingStatus {
+    /// The batch is still processing requests.
+    InProgress,
+    /// The batch is in the process of being canceled.
+    ///
+    /// Requests that are already finished will still have results; any
+    /// remaining requests will be marked as canceled once cancellation
+    /// completes.
+    Canceling,
+    /// Processing for the batch has ended, successfully or not, for every
+    /// request.
+    Ended,
+}
+
+impl Display for ProcessingStatus {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ProcessingStatus::InProgress => write!(f, "{}", "in_progress"),
+            | ProcessingStatus::Canceling => write!(f, "{}", "canceling"),
+            | ProcessingStatus::Ended => write!(f, "{}", "ended"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ProcessingStatus,
+    InProgress => "in_progress",
+    Canceling => "canceling",
+    Ended => "ended"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn processing_status_display() {
+        assert_eq!(ProcessingStatus::InProgress.to_string(), "in_progress");
+        assert_eq!(ProcessingStatus::Canceling.to_string(), "canceling");
+        assert_eq!(ProcessingStatus::Ended.to_string(), "ended");
+    }
+
+    #[test]
+    fn processing_status_serialize() {
+        assert_eq!(
+            serde_json::to_string(&ProcessingStatus::Ended).unwrap(),
+            "\"ended\""
+        );
+    }
+
+    #[test]
+    fn processing_status_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<ProcessingStatus>("\"in_progress\"")
+                .unwrap(),
+            ProcessingStatus::InProgress
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use crate::messages::MessagesError;
+
+/// A type alias for `Result<T, MessagesError>`.
+///
+/// The Batches API reuses the Messages API's error type since it is served
+/// under the same `/v1/messages` path and fails in the same ways.
+pub type BatchesResult<T> = Result<T, MessagesError>;
@@ -0,0 +1,252 @@
+//! The HTTP client used to talk to the Anthropic API.
+
+mod retry_policy;
+
+pub use retry_policy::RetryPolicy;
+
+use std::time::Duration;
+
+const DEFAULT_BASE_URL: &str = "https://api.anthropic.com";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// A client for the Anthropic API.
+///
+/// Construct one with [`Client::new`], or [`Client::builder`] to customize
+/// the base URL or [retry policy](RetryPolicy).
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+}
+
+impl Client {
+    /// Creates a new client with the default base URL and retry policy.
+    pub fn new<S>(api_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::builder(api_key).build()
+    }
+
+    /// Creates a [`ClientBuilder`] to customize the client before building
+    /// it.
+    pub fn builder<S>(api_key: S) -> ClientBuilder
+    where
+        S: Into<String>,
+    {
+        ClientBuilder::new(api_key)
+    }
+
+    pub(crate) fn post_json<T>(
+        &self,
+        path: &str,
+        body: &T,
+    ) -> Result<reqwest::RequestBuilder, reqwest::Error>
+    where
+        T: serde::Serialize,
+    {
+        Ok(self
+            .http_client
+            .post(format!("{}{}", self.base_url, path))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(body))
+    }
+
+    pub(crate) fn get(
+        &self,
+        path: &str,
+    ) -> Result<reqwest::RequestBuilder, reqwest::Error> {
+        Ok(self
+            .http_client
+            .get(format!("{}{}", self.base_url, path))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION))
+    }
+
+    /// Sends `request`, retrying on rate-limit (429) and transient
+    /// overloaded/5xx responses according to [`RetryPolicy`].
+    ///
+    /// Streaming requests should call `request.send()` directly instead:
+    /// retrying a stream after it has started emitting chunks would produce
+    /// a corrupt, partially-duplicated response, so this is only correct
+    /// for non-streaming requests.
+    pub(crate) async fn send_with_retry(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let mut attempt = 0;
+
+        loop {
+            let Some(next_request) = request.try_clone() else {
+                // The request body can't be cloned (e.g. a stream); retrying
+                // it would be unsound, so send it once and return whatever
+                // happens.
+                return request.send().await;
+            };
+
+            let response = next_request.send().await?;
+
+            if attempt >= self.retry_policy.max_retries
+                || !Self::is_retryable(response.status())
+            {
+                return Ok(response);
+            }
+
+            let delay = self
+                .retry_policy
+                .delay_for_attempt(attempt, response.headers().get("retry-after"));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    pub(crate) async fn response_text(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<String, reqwest::Error> {
+        response.text().await
+    }
+
+    pub(crate) async fn parse_response<T, E>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<T, E>
+    where
+        T: serde::de::DeserializeOwned,
+        E: serde::de::DeserializeOwned + From<reqwest::Error>,
+    {
+        if response.status().is_success() {
+            response
+                .json::<T>()
+                .await
+                .map_err(E::from)
+        } else {
+            let error = response
+                .json::<E>()
+                .await
+                .map_err(E::from)?;
+            Err(error)
+        }
+    }
+
+    fn is_retryable(status: reqwest::StatusCode) -> bool {
+        status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || status.as_u16() == 529 // overloaded_error
+            || status.is_server_error()
+    }
+}
+
+/// Builds a [`Client`] with a non-default base URL and/or [`RetryPolicy`].
+#[derive(Debug, Clone)]
+pub struct ClientBuilder {
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+}
+
+impl ClientBuilder {
+    fn new<S>(api_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            api_key: api_key.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Overrides the base URL requests are sent to. Mainly useful for
+    /// testing against a local mock server.
+    pub fn with_base_url<S>(
+        mut self,
+        base_url: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the maximum number of retries for rate-limited or transiently
+    /// failed non-streaming requests. Defaults to
+    /// [`RetryPolicy::DEFAULT_MAX_RETRIES`].
+    pub fn with_max_retries(
+        mut self,
+        max_retries: u32,
+    ) -> Self {
+        self.retry_policy.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the base delay used to compute exponential backoff between
+    /// retries. Defaults to [`RetryPolicy::DEFAULT_BASE_DELAY`].
+    pub fn with_retry_base_delay(
+        mut self,
+        base_delay: Duration,
+    ) -> Self {
+        self.retry_policy.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the maximum delay between retries, capping the exponential
+    /// backoff. Defaults to [`RetryPolicy::DEFAULT_MAX_DELAY`].
+    pub fn with_retry_max_delay(
+        mut self,
+        max_delay: Duration,
+    ) -> Self {
+        self.retry_policy.max_delay = max_delay;
+        self
+    }
+
+    /// Builds the [`Client`].
+    pub fn build(self) -> Client {
+        Client {
+            http_client: reqwest::Client::new(),
+            api_key: self.api_key,
+            base_url: self.base_url,
+            retry_policy: self.retry_policy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_defaults() {
+        let client = Client::builder("sk-ant-test").build();
+        assert_eq!(client.base_url, DEFAULT_BASE_URL);
+        assert_eq!(
+            client.retry_policy.max_retries,
+            RetryPolicy::DEFAULT_MAX_RETRIES
+        );
+    }
+
+    #[test]
+    fn builder_overrides() {
+        let client = Client::builder("sk-ant-test")
+            .with_base_url("http://localhost:8080")
+            .with_max_retries(5)
+            .build();
+        assert_eq!(client.base_url, "http://localhost:8080");
+        assert_eq!(client.retry_policy.max_retries, 5);
+    }
+
+    #[test]
+    fn is_retryable() {
+        assert!(Client::is_retryable(
+            reqwest::StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(Client::is_retryable(
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR
+        ));
+        assert!(!Client::is_retryable(reqwest::StatusCode::BAD_REQUEST));
+        assert!(!Client::is_retryable(reqwest::StatusCode::OK));
+    }
+}
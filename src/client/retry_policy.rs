@@ -0,0 +1,127 @@
+use reqwest::header::HeaderValue;
+use std::time::Duration;
+
+/// Controls how [`Client`](crate::client::Client) retries non-streaming
+/// requests that come back rate-limited (HTTP 429) or with a transient
+/// overloaded/5xx error.
+///
+/// Delay grows exponentially with the attempt number and is randomized
+/// (full jitter) to avoid every client retrying in lockstep. A
+/// server-provided `retry-after` header always takes priority over the
+/// computed delay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub(crate) max_retries: u32,
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// The default maximum number of retries.
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+    /// The default base delay used to compute exponential backoff.
+    pub const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(500);
+    /// The default maximum delay between retries.
+    pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+    /// Creates a retry policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Self::DEFAULT_BASE_DELAY,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+        }
+    }
+
+    pub(crate) fn delay_for_attempt(
+        &self,
+        attempt: u32,
+        retry_after: Option<&HeaderValue>,
+    ) -> Duration {
+        if let Some(retry_after) = retry_after.and_then(Self::parse_retry_after)
+        {
+            return retry_after.min(self.max_delay);
+        }
+
+        let exponential = self
+            .base_delay
+            .saturating_mul(1 << attempt.min(16));
+        let capped = exponential.min(self.max_delay);
+        Self::jittered(capped)
+    }
+
+    fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+        value
+            .to_str()
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(Duration::from_secs)
+    }
+
+    fn jittered(delay: Duration) -> Duration {
+        // Full jitter: a uniformly random delay between 0 and `delay`,
+        // using the current time as an entropy source so we don't need to
+        // pull in a dedicated RNG dependency for one random number.
+        let millis = delay.as_millis() as u64;
+        if millis == 0 {
+            return delay;
+        }
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or(0);
+        Duration::from_millis(seed % (millis + 1))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            base_delay: Self::DEFAULT_BASE_DELAY,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.max_retries, RetryPolicy::DEFAULT_MAX_RETRIES);
+        assert_eq!(policy.base_delay, RetryPolicy::DEFAULT_BASE_DELAY);
+        assert_eq!(policy.max_delay, RetryPolicy::DEFAULT_MAX_DELAY);
+    }
+
+    #[test]
+    fn none_never_retries() {
+        assert_eq!(RetryPolicy::none().max_retries, 0);
+    }
+
+    #[test]
+    fn delay_for_attempt_caps_at_max_delay() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+        };
+        // A high attempt number would overflow exponential growth well past
+        // max_delay; the result must still be capped.
+        assert!(policy.delay_for_attempt(10, None) <= Duration::from_secs(5));
+    }
+
+    #[test]
+    fn delay_for_attempt_honors_retry_after() {
+        let policy = RetryPolicy::default();
+        let retry_after = HeaderValue::from_static("2");
+        assert_eq!(
+            policy.delay_for_attempt(0, Some(&retry_after)),
+            Duration::from_secs(2)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! The [Messages API](https://docs.anthropic.com/claude/reference/messages_post) implementations.
 
+mod accumulator;
 mod chunk_stream;
 mod claude_model;
 mod content;
@@ -17,12 +18,15 @@ mod stream_chunk;
 mod stream_option;
 mod system_prompt;
 mod temperature;
+mod tool;
 mod top_k;
 mod top_p;
 mod usage;
 
 pub(crate) mod api;
 
+pub use accumulator::AccumulatorError;
+pub use accumulator::MessageAccumulator;
 pub use claude_model::ClaudeModel;
 pub use content::Content;
 pub use content::ContentBlock;
@@ -33,6 +37,9 @@ pub use content::ImageMediaType;
 pub use content::ImageSourceType;
 pub use content::TextContentBlock;
 pub use content::TextDeltaContentBlock;
+pub use content::ToolInputError;
+pub use content::ToolResultContentBlock;
+pub use content::ToolUseContentBlock;
 pub use error::MessagesError;
 pub use error::StreamError;
 pub use max_tokens::MaxTokens;
@@ -47,10 +54,12 @@ pub use result::MessagesResult;
 pub use role::Role;
 pub use stop_reason::StopReason;
 pub use stop_sequence::StopSequence;
+pub use stream_chunk::ContentBlockDelta;
 pub use stream_chunk::ContentBlockDeltaChunk;
 pub use stream_chunk::ContentBlockStartChunk;
 pub use stream_chunk::ContentBlockStopChunk;
 pub use stream_chunk::DeltaUsage;
+pub use stream_chunk::InputJsonDeltaContentBlock;
 pub use stream_chunk::MessageDeltaChunk;
 pub use stream_chunk::MessageStartChunk;
 pub use stream_chunk::MessageStopChunk;
@@ -61,6 +70,8 @@ pub use stream_chunk::StreamStop;
 pub use stream_option::StreamOption;
 pub use system_prompt::SystemPrompt;
 pub use temperature::Temperature;
+pub use tool::Tool;
+pub use tool::ToolChoice;
 pub use top_k::TopK;
 pub use top_p::TopP;
 pub use usage::Usage;
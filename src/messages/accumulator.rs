@@ -0,0 +1,428 @@
+use crate::messages::{
+    ClaudeModel, Content, ContentBlock, ContentBlockDelta, ImageContentBlock,
+    MessageObjectType, MessagesResponseBody, Role, StopReason, StopSequence,
+    StreamChunk, TextContentBlock, ToolUseContentBlock, Usage,
+};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// Folds the [`StreamChunk`] events of a streamed Messages API response back
+/// into a single [`MessagesResponseBody`], the same type a non-streaming
+/// call would have returned.
+///
+/// Feed every chunk, in order, to [`push`](Self::push). It routes deltas by
+/// their `index`, so interleaved content blocks are reconstructed
+/// correctly. `push` returns the completed response once it has processed
+/// the stream's `message_stop` event.
+///
+/// ```ignore
+/// let mut accumulator = MessageAccumulator::new();
+/// let mut response = None;
+/// while let Some(chunk) = stream.next().await {
+///     response = accumulator.push(chunk?)?;
+/// }
+/// let response = response.expect("stream ended without message_stop");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageAccumulator {
+    id: String,
+    model: ClaudeModel,
+    role: Role,
+    input_tokens: u32,
+    output_tokens: u32,
+    stop_reason: Option<StopReason>,
+    stop_sequence: Option<StopSequence>,
+    blocks: BTreeMap<usize, PartialContentBlock>,
+}
+
+#[derive(Debug, Clone)]
+enum PartialContentBlock {
+    Text(String),
+    Image(ImageContentBlock),
+    ToolUse {
+        id: String,
+        name: String,
+        partial_json: String,
+    },
+}
+
+impl MessageAccumulator {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the next chunk of the stream into the accumulator.
+    ///
+    /// Returns `Ok(Some(response))` once the `message_stop` event has been
+    /// processed, `Ok(None)` otherwise.
+    pub fn push(
+        &mut self,
+        chunk: StreamChunk,
+    ) -> Result<Option<MessagesResponseBody>, AccumulatorError> {
+        match chunk {
+            | StreamChunk::MessageStart(chunk) => {
+                self.id = chunk.message.id;
+                self.model = chunk.message.model;
+                self.role = chunk.message.role;
+                self.input_tokens = chunk.message.usage.input_tokens;
+                Ok(None)
+            },
+            | StreamChunk::ContentBlockStart(chunk) => {
+                self.blocks.insert(
+                    chunk.index,
+                    PartialContentBlock::from(chunk.content_block),
+                );
+                Ok(None)
+            },
+            | StreamChunk::ContentBlockDelta(chunk) => {
+                let block = self
+                    .blocks
+                    .get_mut(&chunk.index)
+                    .ok_or(AccumulatorError::UnknownContentBlockIndex(
+                        chunk.index,
+                    ))?;
+                block.apply_delta(chunk.delta);
+                Ok(None)
+            },
+            | StreamChunk::ContentBlockStop(_) => Ok(None),
+            | StreamChunk::MessageDelta(chunk) => {
+                self.stop_reason = chunk.delta.stop_reason;
+                self.stop_sequence = chunk.delta.stop_sequence;
+                self.output_tokens = chunk.usage.output_tokens;
+                Ok(None)
+            },
+            | StreamChunk::MessageStop(_) => {
+                Ok(Some(self.clone().into_response()?))
+            },
+            | StreamChunk::Ping(_) => Ok(None),
+        }
+    }
+
+    fn into_response(
+        self
+    ) -> Result<MessagesResponseBody, AccumulatorError> {
+        let content = self
+            .blocks
+            .into_values()
+            .map(PartialContentBlock::into_content_block)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(MessagesResponseBody {
+            id: self.id,
+            _type: MessageObjectType::Message,
+            role: self.role,
+            content: Content::from(content),
+            model: self.model,
+            stop_reason: self.stop_reason,
+            stop_sequence: self.stop_sequence,
+            usage: Usage {
+                input_tokens: self.input_tokens,
+                output_tokens: self.output_tokens,
+            },
+        })
+    }
+}
+
+impl PartialContentBlock {
+    fn apply_delta(
+        &mut self,
+        delta: ContentBlockDelta,
+    ) {
+        match (self, delta) {
+            | (
+                PartialContentBlock::Text(text),
+                ContentBlockDelta::TextDelta(delta),
+            ) => {
+                text.push_str(&delta.text);
+            },
+            | (
+                PartialContentBlock::ToolUse { partial_json, .. },
+                ContentBlockDelta::InputJsonDelta(delta),
+            ) => {
+                partial_json.push_str(&delta.partial_json);
+            },
+            | _ => {},
+        }
+    }
+
+    fn into_content_block(
+        self
+    ) -> Result<ContentBlock, AccumulatorError> {
+        match self {
+            | PartialContentBlock::Text(text) => {
+                Ok(ContentBlock::Text(TextContentBlock {
+                    _type: crate::messages::ContentType::Text,
+                    text,
+                }))
+            },
+            | PartialContentBlock::Image(image) => {
+                Ok(ContentBlock::Image(image))
+            },
+            | PartialContentBlock::ToolUse {
+                id,
+                name,
+                partial_json,
+            } => {
+                let input = if partial_json.is_empty() {
+                    serde_json::Value::Object(Default::default())
+                } else {
+                    serde_json::from_str(&partial_json).map_err(
+                        |source| AccumulatorError::InvalidToolInputJson {
+                            tool_name: name.clone(),
+                            source,
+                        },
+                    )?
+                };
+                Ok(ContentBlock::ToolUse(ToolUseContentBlock::new(
+                    id, name, input,
+                )))
+            },
+        }
+    }
+}
+
+impl From<ContentBlock> for PartialContentBlock {
+    fn from(block: ContentBlock) -> Self {
+        match block {
+            | ContentBlock::Text(text) => {
+                PartialContentBlock::Text(text.text)
+            },
+            | ContentBlock::Image(image) => {
+                PartialContentBlock::Image(image)
+            },
+            | ContentBlock::ToolUse(tool_use) => {
+                PartialContentBlock::ToolUse {
+                    id: tool_use.id,
+                    name: tool_use.name,
+                    partial_json: String::new(),
+                }
+            },
+            | ContentBlock::ToolResult(_) => {
+                PartialContentBlock::Text(String::new())
+            },
+        }
+    }
+}
+
+/// An error raised while accumulating a stream into a
+/// [`MessagesResponseBody`].
+#[derive(Debug)]
+pub enum AccumulatorError {
+    /// A `content_block_delta` event referenced a block index that no
+    /// `content_block_start` event had opened. `content_block_stop` events
+    /// are not checked.
+    UnknownContentBlockIndex(usize),
+    /// The concatenated `input_json_delta` deltas for a tool use block did
+    /// not form valid JSON when the `message_stop` event was processed.
+    InvalidToolInputJson {
+        /// The name of the tool whose input failed to parse.
+        tool_name: String,
+        /// The underlying JSON parse error.
+        source: serde_json::Error,
+    },
+}
+
+impl Display for AccumulatorError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | AccumulatorError::UnknownContentBlockIndex(index) => {
+                write!(f, "unknown content block index: {}", index)
+            },
+            | AccumulatorError::InvalidToolInputJson {
+                tool_name,
+                source,
+            } => {
+                write!(
+                    f,
+                    "invalid input JSON for tool `{}`: {}",
+                    tool_name, source
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for AccumulatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            | AccumulatorError::UnknownContentBlockIndex(_) => None,
+            | AccumulatorError::InvalidToolInputJson { source, .. } => {
+                Some(source)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        ContentBlockDeltaChunk, ContentBlockStartChunk, ContentBlockStopChunk,
+        ContentType, DeltaUsage, MessageDeltaChunk, MessageStartChunk,
+        MessageStopChunk, StreamChunkType, StreamStop, TextDeltaContentBlock,
+    };
+
+    fn message_start(id: &str) -> StreamChunk {
+        StreamChunk::MessageStart(MessageStartChunk {
+            _type: StreamChunkType::MessageStart,
+            message: MessagesResponseBody {
+                id: id.to_string(),
+                _type: MessageObjectType::Message,
+                role: Role::Assistant,
+                content: Vec::<ContentBlock>::new().into(),
+                model: ClaudeModel::Claude3Sonnet20240229,
+                stop_reason: None,
+                stop_sequence: None,
+                usage: Usage {
+                    input_tokens: 10,
+                    output_tokens: 0,
+                },
+            },
+        })
+    }
+
+    #[test]
+    fn accumulates_text() {
+        let mut accumulator = MessageAccumulator::new();
+
+        assert_eq!(accumulator.push(message_start("msg_01")).unwrap(), None);
+
+        assert_eq!(
+            accumulator
+                .push(StreamChunk::ContentBlockStart(
+                    ContentBlockStartChunk {
+                        _type: StreamChunkType::ContentBlockStart,
+                        index: 0,
+                        content_block: ContentBlock::Text(
+                            TextContentBlock {
+                                _type: ContentType::Text,
+                                text: String::new(),
+                            }
+                        ),
+                    }
+                ))
+                .unwrap(),
+            None
+        );
+
+        for chunk in ["Hello", ", ", "world"] {
+            accumulator
+                .push(StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk {
+                    _type: StreamChunkType::ContentBlockDelta,
+                    index: 0,
+                    delta: ContentBlockDelta::TextDelta(
+                        TextDeltaContentBlock {
+                            _type: ContentType::TextDelta,
+                            text: chunk.to_string(),
+                        },
+                    ),
+                }))
+                .unwrap();
+        }
+
+        accumulator
+            .push(StreamChunk::ContentBlockStop(ContentBlockStopChunk {
+                _type: StreamChunkType::ContentBlockStop,
+                index: 0,
+            }))
+            .unwrap();
+
+        accumulator
+            .push(StreamChunk::MessageDelta(MessageDeltaChunk {
+                _type: StreamChunkType::MessageDelta,
+                delta: StreamStop {
+                    stop_reason: Some(StopReason::EndTurn),
+                    stop_sequence: None,
+                },
+                usage: DeltaUsage { output_tokens: 5 },
+            }))
+            .unwrap();
+
+        let response = accumulator
+            .push(StreamChunk::MessageStop(MessageStopChunk {
+                _type: StreamChunkType::MessageStop,
+            }))
+            .unwrap()
+            .expect("message_stop should complete the response");
+
+        assert_eq!(response.id, "msg_01");
+        assert_eq!(
+            response.content,
+            Content::from(vec![ContentBlock::Text(TextContentBlock {
+                _type: ContentType::Text,
+                text: "Hello, world".to_string(),
+            })])
+        );
+        assert_eq!(response.stop_reason, Some(StopReason::EndTurn));
+        assert_eq!(response.usage.input_tokens, 10);
+        assert_eq!(response.usage.output_tokens, 5);
+    }
+
+    #[test]
+    fn accumulates_tool_use_input() {
+        let mut accumulator = MessageAccumulator::new();
+        accumulator.push(message_start("msg_01")).unwrap();
+
+        accumulator
+            .push(StreamChunk::ContentBlockStart(ContentBlockStartChunk {
+                _type: StreamChunkType::ContentBlockStart,
+                index: 0,
+                content_block: ContentBlock::ToolUse(
+                    ToolUseContentBlock::new(
+                        "toolu_01",
+                        "get_weather",
+                        serde_json::json!({}),
+                    ),
+                ),
+            }))
+            .unwrap();
+
+        for chunk in ["{\"location\":", "\"Tokyo\"}"] {
+            accumulator
+                .push(StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk {
+                    _type: StreamChunkType::ContentBlockDelta,
+                    index: 0,
+                    delta: ContentBlockDelta::InputJsonDelta(
+                        crate::messages::InputJsonDeltaContentBlock {
+                            _type: ContentType::InputJsonDelta,
+                            partial_json: chunk.to_string(),
+                        },
+                    ),
+                }))
+                .unwrap();
+        }
+
+        accumulator
+            .push(StreamChunk::MessageDelta(MessageDeltaChunk {
+                _type: StreamChunkType::MessageDelta,
+                delta: StreamStop {
+                    stop_reason: Some(StopReason::ToolUse),
+                    stop_sequence: None,
+                },
+                usage: DeltaUsage { output_tokens: 8 },
+            }))
+            .unwrap();
+
+        let response = accumulator
+            .push(StreamChunk::MessageStop(MessageStopChunk {
+                _type: StreamChunkType::MessageStop,
+            }))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            response.content,
+            Content::from(vec![ContentBlock::ToolUse(
+                ToolUseContentBlock::new(
+                    "toolu_01",
+                    "get_weather",
+                    serde_json::json!({ "location": "Tokyo" }),
+                )
+            )])
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! The internal request layer for the Messages API.
+
+use crate::client::Client;
+use crate::messages::{
+    MessagesError, MessagesRequestBody, MessagesResponseBody, MessagesResult,
+};
+
+/// HTTP header used to opt into server-side beta features.
+const ANTHROPIC_BETA_HEADER: &str = "anthropic-beta";
+
+/// Calls the [create a message](https://docs.anthropic.com/claude/reference/messages_post) API.
+pub(crate) async fn create_a_message(
+    client: &Client,
+    request_body: MessagesRequestBody,
+) -> MessagesResult<MessagesResponseBody> {
+    create_a_message_with_betas(client, request_body, &[]).await
+}
+
+/// Calls the create a message API, opting into the given beta features via
+/// the `anthropic-beta` header.
+///
+/// Tool use currently requires no beta header, but this allows new
+/// server-side betas to be threaded through without changing the public
+/// request/response types.
+pub(crate) async fn create_a_message_with_betas(
+    client: &Client,
+    request_body: MessagesRequestBody,
+    betas: &[&str],
+) -> MessagesResult<MessagesResponseBody> {
+    let mut request = client
+        .post_json("/v1/messages", &request_body)
+        .map_err(MessagesError::from)?;
+
+    if !betas.is_empty() {
+        request = request.header(ANTHROPIC_BETA_HEADER, betas.join(","));
+    }
+
+    // Non-streaming requests are safe to retry on rate-limit/overloaded
+    // responses; `send_with_retry` honors the client's `RetryPolicy`.
+    let response = client
+        .send_with_retry(request)
+        .await
+        .map_err(MessagesError::from)?;
+
+    client
+        .parse_response::<MessagesResponseBody, MessagesError>(response)
+        .await
+}
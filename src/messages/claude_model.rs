@@ -0,0 +1,121 @@
+use crate::macros::impl_enum_string_serialization;
+use std::fmt::{Display, Formatter};
+
+/// A Claude model that can complete a [`MessagesRequestBody`](crate::messages::MessagesRequestBody).
+///
+/// See also [the models overview](https://docs.anthropic.com/claude/docs/models-overview).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaudeModel {
+    /// claude-3-opus-20240229
+    Claude3Opus20240229,
+    /// claude-3-sonnet-20240229
+    Claude3Sonnet20240229,
+    /// claude-3-haiku-20240307
+    Claude3Haiku20240307,
+}
+
+impl Default for ClaudeModel {
+    fn default() -> Self {
+        Self::Claude3Sonnet20240229
+    }
+}
+
+impl Display for ClaudeModel {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ClaudeModel::Claude3Opus20240229 => {
+                write!(f, "{}", "claude-3-opus-20240229")
+            },
+            | ClaudeModel::Claude3Sonnet20240229 => {
+                write!(f, "{}", "claude-3-sonnet-20240229")
+            },
+            | ClaudeModel::Claude3Haiku20240307 => {
+                write!(f, "{}", "claude-3-haiku-20240307")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ClaudeModel,
+    Claude3Opus20240229 => "claude-3-opus-20240229",
+    Claude3Sonnet20240229 => "claude-3-sonnet-20240229",
+    Claude3Haiku20240307 => "claude-3-haiku-20240307"
+);
+
+impl ClaudeModel {
+    /// The model identifier used to invoke this model through AWS Bedrock,
+    /// e.g. `anthropic.claude-3-sonnet-20240229-v1:0`.
+    ///
+    /// See also [the Bedrock model IDs reference](https://docs.aws.amazon.com/bedrock/latest/userguide/model-ids.html).
+    pub fn bedrock_model_id(&self) -> &'static str {
+        match self {
+            | ClaudeModel::Claude3Opus20240229 => {
+                "anthropic.claude-3-opus-20240229-v1:0"
+            },
+            | ClaudeModel::Claude3Sonnet20240229 => {
+                "anthropic.claude-3-sonnet-20240229-v1:0"
+            },
+            | ClaudeModel::Claude3Haiku20240307 => {
+                "anthropic.claude-3-haiku-20240307-v1:0"
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_claude_model() {
+        assert_eq!(ClaudeModel::default(), ClaudeModel::Claude3Sonnet20240229);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ClaudeModel::Claude3Opus20240229.to_string(),
+            "claude-3-opus-20240229"
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&ClaudeModel::Claude3Haiku20240307)
+                .unwrap(),
+            "\"claude-3-haiku-20240307\""
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<ClaudeModel>(
+                "\"claude-3-sonnet-20240229\""
+            )
+            .unwrap(),
+            ClaudeModel::Claude3Sonnet20240229
+        );
+    }
+
+    #[test]
+    fn bedrock_model_id() {
+        assert_eq!(
+            ClaudeModel::Claude3Sonnet20240229.bedrock_model_id(),
+            "anthropic.claude-3-sonnet-20240229-v1:0"
+        );
+        assert_eq!(
+            ClaudeModel::Claude3Opus20240229.bedrock_model_id(),
+            "anthropic.claude-3-opus-20240229-v1:0"
+        );
+        assert_eq!(
+            ClaudeModel::Claude3Haiku20240307.bedrock_model_id(),
+            "anthropic.claude-3-haiku-20240307-v1:0"
+        );
+    }
+}
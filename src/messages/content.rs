@@ -0,0 +1,433 @@
+use crate::macros::impl_enum_string_serialization;
+use std::fmt::{Display, Formatter};
+
+/// The content of the message.
+///
+/// This can be a single string, or an array of content blocks when the
+/// message is made of multiple parts (text, images, tool use, tool results).
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(untagged)]
+pub enum Content {
+    /// A single text content.
+    SingleText(String),
+    /// Multiple content blocks.
+    MultipleBlock(Vec<ContentBlock>),
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::SingleText(String::default())
+    }
+}
+
+impl From<&str> for Content {
+    fn from(text: &str) -> Self {
+        Self::SingleText(text.to_string())
+    }
+}
+
+impl From<String> for Content {
+    fn from(text: String) -> Self {
+        Self::SingleText(text)
+    }
+}
+
+impl From<Vec<ContentBlock>> for Content {
+    fn from(blocks: Vec<ContentBlock>) -> Self {
+        Self::MultipleBlock(blocks)
+    }
+}
+
+/// A content block.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(untagged)]
+pub enum ContentBlock {
+    /// Text content block.
+    Text(TextContentBlock),
+    /// Image content block.
+    Image(ImageContentBlock),
+    /// Tool use content block.
+    ToolUse(ToolUseContentBlock),
+    /// Tool result content block.
+    ToolResult(ToolResultContentBlock),
+}
+
+/// The type of the content block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentType {
+    /// text
+    Text,
+    /// text_delta
+    TextDelta,
+    /// image
+    Image,
+    /// tool_use
+    ToolUse,
+    /// tool_result
+    ToolResult,
+    /// input_json_delta
+    InputJsonDelta,
+}
+
+impl Display for ContentType {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ContentType::Text => write!(f, "{}", "text"),
+            | ContentType::TextDelta => write!(f, "{}", "text_delta"),
+            | ContentType::Image => write!(f, "{}", "image"),
+            | ContentType::ToolUse => write!(f, "{}", "tool_use"),
+            | ContentType::ToolResult => write!(f, "{}", "tool_result"),
+            | ContentType::InputJsonDelta => {
+                write!(f, "{}", "input_json_delta")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ContentType,
+    Text => "text",
+    TextDelta => "text_delta",
+    Image => "image",
+    ToolUse => "tool_use",
+    ToolResult => "tool_result",
+    InputJsonDelta => "input_json_delta"
+);
+
+/// A text content block.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct TextContentBlock {
+    /// The type of the content block.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The text content.
+    pub text: String,
+}
+
+/// A streamed text delta content block.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct TextDeltaContentBlock {
+    /// The type of the content block.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The partial text content.
+    pub text: String,
+}
+
+/// An image content block.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ImageContentBlock {
+    /// The type of the content block.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The source of the image.
+    pub source: ImageContentSource,
+}
+
+/// The source of an image content block.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ImageContentSource {
+    /// The type of the image source.
+    #[serde(rename = "type")]
+    pub _type: ImageSourceType,
+    /// The media type of the image.
+    pub media_type: ImageMediaType,
+    /// The base64-encoded image data.
+    pub data: String,
+}
+
+/// The type of an image source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSourceType {
+    /// base64
+    Base64,
+}
+
+impl Display for ImageSourceType {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageSourceType::Base64 => write!(f, "{}", "base64"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageSourceType,
+    Base64 => "base64"
+);
+
+/// The media type of an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageMediaType {
+    /// image/jpeg
+    Jpeg,
+    /// image/png
+    Png,
+    /// image/gif
+    Gif,
+    /// image/webp
+    Webp,
+}
+
+impl Display for ImageMediaType {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageMediaType::Jpeg => write!(f, "{}", "image/jpeg"),
+            | ImageMediaType::Png => write!(f, "{}", "image/png"),
+            | ImageMediaType::Gif => write!(f, "{}", "image/gif"),
+            | ImageMediaType::Webp => write!(f, "{}", "image/webp"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageMediaType,
+    Jpeg => "image/jpeg",
+    Png => "image/png",
+    Gif => "image/gif",
+    Webp => "image/webp"
+);
+
+/// A tool use content block emitted by the assistant when it wants to
+/// invoke a tool.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ToolUseContentBlock {
+    /// The type of the content block.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The unique identifier of this tool use.
+    pub id: String,
+    /// The name of the tool being invoked.
+    pub name: String,
+    /// The input to the tool, matching its `input_schema`.
+    pub input: serde_json::Value,
+}
+
+impl ToolUseContentBlock {
+    /// Creates a new tool use content block.
+    pub fn new<S>(
+        id: S,
+        name: S,
+        input: serde_json::Value,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::ToolUse,
+            id: id.into(),
+            name: name.into(),
+            input,
+        }
+    }
+
+    /// Deserializes `input` into a typed Rust value.
+    ///
+    /// This is the counterpart of
+    /// [`Tool::from_type`](crate::messages::Tool::from_type): define the
+    /// tool's arguments as a plain struct and use this to get them back out
+    /// of the content block Claude sent, instead of matching on
+    /// `serde_json::Value` by hand.
+    pub fn input_as<T>(&self) -> Result<T, ToolInputError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_value(self.input.clone())
+            .map_err(|source| ToolInputError {
+                tool_name: self.name.clone(),
+                source,
+            })
+    }
+}
+
+/// An error returned when a [`ToolUseContentBlock`]'s `input` does not
+/// match the Rust type it was deserialized into.
+#[derive(Debug)]
+pub struct ToolInputError {
+    tool_name: String,
+    source: serde_json::Error,
+}
+
+impl Display for ToolInputError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to deserialize input for tool `{}`: {}",
+            self.tool_name, self.source
+        )
+    }
+}
+
+impl std::error::Error for ToolInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// A tool result content block sent back to Claude with the output of a
+/// tool invocation.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ToolResultContentBlock {
+    /// The type of the content block.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The `id` of the tool use this result is for.
+    pub tool_use_id: String,
+    /// The result of the tool invocation.
+    pub content: Content,
+    /// Whether the tool invocation resulted in an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_error: Option<bool>,
+}
+
+impl ToolResultContentBlock {
+    /// Creates a new tool result content block.
+    pub fn new<S>(
+        tool_use_id: S,
+        content: Content,
+        is_error: Option<bool>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::ToolResult,
+            tool_use_id: tool_use_id.into(),
+            content,
+            is_error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_from_str() {
+        assert_eq!(
+            Content::from("content"),
+            Content::SingleText("content".to_string())
+        );
+    }
+
+    #[test]
+    fn content_default() {
+        assert_eq!(
+            Content::default(),
+            Content::SingleText(String::default())
+        );
+    }
+
+    #[test]
+    fn tool_use_content_block_serialize() {
+        let block = ToolUseContentBlock::new(
+            "toolu_01",
+            "get_weather",
+            serde_json::json!({ "location": "Tokyo" }),
+        );
+        assert_eq!(
+            serde_json::to_string(&block).unwrap(),
+            "{\"type\":\"tool_use\",\"id\":\"toolu_01\",\"name\":\"get_weather\",\"input\":{\"location\":\"Tokyo\"}}"
+        );
+    }
+
+    #[test]
+    fn tool_result_content_block_serialize() {
+        let block = ToolResultContentBlock::new(
+            "toolu_01",
+            Content::from("22 degrees celsius"),
+            None,
+        );
+        assert_eq!(
+            serde_json::to_string(&block).unwrap(),
+            "{\"type\":\"tool_result\",\"tool_use_id\":\"toolu_01\",\"content\":\"22 degrees celsius\"}"
+        );
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct GetWeatherInput {
+        location: String,
+    }
+
+    #[test]
+    fn tool_use_content_block_input_as() {
+        let block = ToolUseContentBlock::new(
+            "toolu_01",
+            "get_weather",
+            serde_json::json!({ "location": "Tokyo" }),
+        );
+        assert_eq!(
+            block
+                .input_as::<GetWeatherInput>()
+                .unwrap(),
+            GetWeatherInput {
+                location: "Tokyo".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn tool_use_content_block_input_as_error() {
+        let block = ToolUseContentBlock::new(
+            "toolu_01",
+            "get_weather",
+            serde_json::json!({ "wrong_field": "Tokyo" }),
+        );
+        assert!(
+            block
+                .input_as::<GetWeatherInput>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn content_type_display() {
+        assert_eq!(ContentType::ToolUse.to_string(), "tool_use");
+        assert_eq!(ContentType::ToolResult.to_string(), "tool_result");
+    }
+
+    #[test]
+    fn content_type_serialize() {
+        assert_eq!(
+            serde_json::to_string(&ContentType::ToolUse).unwrap(),
+            "\"tool_use\""
+        );
+    }
+
+    #[test]
+    fn content_type_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"tool_result\"").unwrap(),
+            ContentType::ToolResult
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::macros::impl_display_for_serialize;
+use crate::messages::{
+    ClaudeModel, MaxTokens, Message, Metadata, StopSequence, StreamOption,
+    SystemPrompt, Temperature, Tool, ToolChoice, TopK, TopP,
+};
+
+/// The request body for the Messages API.
+///
+/// See also [the Messages API](https://docs.anthropic.com/claude/reference/messages_post).
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessagesRequestBody {
+    /// The model that will complete your prompt.
+    pub model: ClaudeModel,
+    /// Input messages.
+    pub messages: Vec<Message>,
+    /// The maximum number of tokens to generate before stopping.
+    pub max_tokens: MaxTokens,
+    /// An object describing metadata about the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Custom text sequences that will cause the model to stop generating.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<Vec<StopSequence>>,
+    /// Whether to incrementally stream the response using server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<StreamOption>,
+    /// System prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<SystemPrompt>,
+    /// Amount of randomness injected into the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+    /// Definitions of tools that Claude may call while generating a response.
+    ///
+    /// See also [the tool use guide](https://docs.anthropic.com/claude/docs/tool-use).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    /// How the model should decide whether and which tool to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
+    /// Only sample from the top K options for each subsequent token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<TopK>,
+    /// Use nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+}
+
+impl_display_for_serialize!(MessagesRequestBody);
+
+impl MessagesRequestBody {
+    /// Creates a new request body with the required fields only.
+    pub fn new(
+        model: ClaudeModel,
+        messages: Vec<Message>,
+        max_tokens: MaxTokens,
+    ) -> Self {
+        Self {
+            model,
+            messages,
+            max_tokens,
+            metadata: None,
+            stop_sequences: None,
+            stream: None,
+            system: None,
+            temperature: None,
+            tools: None,
+            tool_choice: None,
+            top_k: None,
+            top_p: None,
+        }
+    }
+
+    /// Sets the tools that Claude may call while generating a response.
+    pub fn with_tools(
+        mut self,
+        tools: Vec<Tool>,
+    ) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    /// Sets how the model should decide whether and which tool to use.
+    pub fn with_tool_choice(
+        mut self,
+        tool_choice: ToolChoice,
+    ) -> Self {
+        self.tool_choice = Some(tool_choice);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::Role;
+
+    fn message(text: &str) -> Message {
+        Message {
+            role: Role::User,
+            content: text.into(),
+        }
+    }
+
+    #[test]
+    fn new() {
+        let request = MessagesRequestBody::new(
+            ClaudeModel::Claude3Sonnet20240229,
+            vec![message("hello")],
+            MaxTokens::new(1024),
+        );
+        assert_eq!(request.tools, None);
+        assert_eq!(request.tool_choice, None);
+    }
+
+    #[test]
+    fn with_tools() {
+        let tool = Tool::new(
+            "get_weather",
+            "Get the current weather in a location",
+            serde_json::json!({ "type": "object" }),
+        );
+        let request = MessagesRequestBody::new(
+            ClaudeModel::Claude3Sonnet20240229,
+            vec![message("hello")],
+            MaxTokens::new(1024),
+        )
+        .with_tools(vec![tool.clone()])
+        .with_tool_choice(ToolChoice::Auto);
+        assert_eq!(request.tools, Some(vec![tool]));
+        assert_eq!(request.tool_choice, Some(ToolChoice::Auto));
+    }
+
+    #[test]
+    fn serialize_without_tools() {
+        let request = MessagesRequestBody::new(
+            ClaudeModel::Claude3Sonnet20240229,
+            vec![message("hello")],
+            MaxTokens::new(1024),
+        );
+        let value = serde_json::to_value(&request).unwrap();
+        assert!(value.get("tools").is_none());
+        assert!(value.get("tool_choice").is_none());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::macros::impl_enum_string_serialization;
+use std::fmt::{Display, Formatter};
+
+/// The reason that the model stopped generating.
+///
+/// See also [the Messages API](https://docs.anthropic.com/claude/reference/messages_post).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The model reached a natural stopping point.
+    EndTurn,
+    /// We exceeded the requested `max_tokens` or the model's maximum.
+    MaxTokens,
+    /// One of the provided custom `stop_sequences` was generated.
+    StopSequence,
+    /// The model invoked one or more tools and is waiting for their results.
+    ToolUse,
+}
+
+impl Display for StopReason {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | StopReason::EndTurn => write!(f, "{}", "end_turn"),
+            | StopReason::MaxTokens => write!(f, "{}", "max_tokens"),
+            | StopReason::StopSequence => write!(f, "{}", "stop_sequence"),
+            | StopReason::ToolUse => write!(f, "{}", "tool_use"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    StopReason,
+    EndTurn => "end_turn",
+    MaxTokens => "max_tokens",
+    StopSequence => "stop_sequence",
+    ToolUse => "tool_use"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_reason_display() {
+        assert_eq!(StopReason::EndTurn.to_string(), "end_turn");
+        assert_eq!(StopReason::MaxTokens.to_string(), "max_tokens");
+        assert_eq!(StopReason::StopSequence.to_string(), "stop_sequence");
+        assert_eq!(StopReason::ToolUse.to_string(), "tool_use");
+    }
+
+    #[test]
+    fn stop_reason_serialize() {
+        assert_eq!(
+            serde_json::to_string(&StopReason::ToolUse).unwrap(),
+            "\"tool_use\""
+        );
+    }
+
+    #[test]
+    fn stop_reason_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<StopReason>("\"tool_use\"").unwrap(),
+            StopReason::ToolUse
+        );
+    }
+}
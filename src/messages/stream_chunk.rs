@@ -0,0 +1,360 @@
+use crate::macros::impl_enum_string_serialization;
+use crate::messages::{
+    ContentBlock, ContentType, MessagesResponseBody, StopReason,
+    StopSequence, TextDeltaContentBlock,
+};
+use std::fmt::{Display, Formatter};
+
+/// A single server-sent event emitted while streaming a Messages API
+/// response.
+///
+/// See also [streaming Messages](https://docs.anthropic.com/claude/reference/messages-streaming).
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(tag = "type")]
+pub enum StreamChunk {
+    /// `message_start`
+    #[serde(rename = "message_start")]
+    MessageStart(MessageStartChunk),
+    /// `content_block_start`
+    #[serde(rename = "content_block_start")]
+    ContentBlockStart(ContentBlockStartChunk),
+    /// `content_block_delta`
+    #[serde(rename = "content_block_delta")]
+    ContentBlockDelta(ContentBlockDeltaChunk),
+    /// `content_block_stop`
+    #[serde(rename = "content_block_stop")]
+    ContentBlockStop(ContentBlockStopChunk),
+    /// `message_delta`
+    #[serde(rename = "message_delta")]
+    MessageDelta(MessageDeltaChunk),
+    /// `message_stop`
+    #[serde(rename = "message_stop")]
+    MessageStop(MessageStopChunk),
+    /// `ping`
+    #[serde(rename = "ping")]
+    Ping(PingChunk),
+}
+
+/// The type of a [`StreamChunk`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamChunkType {
+    /// message_start
+    MessageStart,
+    /// content_block_start
+    ContentBlockStart,
+    /// content_block_delta
+    ContentBlockDelta,
+    /// content_block_stop
+    ContentBlockStop,
+    /// message_delta
+    MessageDelta,
+    /// message_stop
+    MessageStop,
+    /// ping
+    Ping,
+}
+
+impl Display for StreamChunkType {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | StreamChunkType::MessageStart => {
+                write!(f, "{}", "message_start")
+            },
+            | StreamChunkType::ContentBlockStart => {
+                write!(f, "{}", "content_block_start")
+            },
+            | StreamChunkType::ContentBlockDelta => {
+                write!(f, "{}", "content_block_delta")
+            },
+            | StreamChunkType::ContentBlockStop => {
+                write!(f, "{}", "content_block_stop")
+            },
+            | StreamChunkType::MessageDelta => {
+                write!(f, "{}", "message_delta")
+            },
+            | StreamChunkType::MessageStop => {
+                write!(f, "{}", "message_stop")
+            },
+            | StreamChunkType::Ping => write!(f, "{}", "ping"),
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    StreamChunkType,
+    MessageStart => "message_start",
+    ContentBlockStart => "content_block_start",
+    ContentBlockDelta => "content_block_delta",
+    ContentBlockStop => "content_block_stop",
+    MessageDelta => "message_delta",
+    MessageStop => "message_stop",
+    Ping => "ping"
+);
+
+/// The first event of a stream, carrying a `message` with empty content and
+/// no `stop_reason` yet.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageStartChunk {
+    /// The type of the chunk.
+    #[serde(rename = "type")]
+    pub _type: StreamChunkType,
+    /// The initial message, with empty `content` and no `stop_reason`.
+    pub message: MessagesResponseBody,
+}
+
+/// Emitted when a new content block starts at `index`.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContentBlockStartChunk {
+    /// The type of the chunk.
+    #[serde(rename = "type")]
+    pub _type: StreamChunkType,
+    /// The index of the content block this event applies to.
+    pub index: usize,
+    /// The initial, possibly empty, content block.
+    pub content_block: ContentBlock,
+}
+
+/// Emitted when the content block at `index` stops.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContentBlockStopChunk {
+    /// The type of the chunk.
+    #[serde(rename = "type")]
+    pub _type: StreamChunkType,
+    /// The index of the content block this event applies to.
+    pub index: usize,
+}
+
+/// A delta to apply to the content block at `index`.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContentBlockDeltaChunk {
+    /// The type of the chunk.
+    #[serde(rename = "type")]
+    pub _type: StreamChunkType,
+    /// The index of the content block this event applies to.
+    pub index: usize,
+    /// The delta to apply.
+    pub delta: ContentBlockDelta,
+}
+
+/// The delta carried by a [`ContentBlockDeltaChunk`].
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(untagged)]
+pub enum ContentBlockDelta {
+    /// A partial chunk of assistant text.
+    TextDelta(TextDeltaContentBlock),
+    /// A partial chunk of a tool use's JSON `input`, to be concatenated
+    /// with previous deltas for the same block and parsed once complete.
+    InputJsonDelta(InputJsonDeltaContentBlock),
+}
+
+/// A partial chunk of a tool use's JSON `input`.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct InputJsonDeltaContentBlock {
+    /// The type of the delta.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The partial JSON string; concatenate across deltas for the same
+    /// block index and parse once the block stops.
+    pub partial_json: String,
+}
+
+/// Carries the final `stop_reason`, `stop_sequence` and cumulative usage
+/// for the message.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageDeltaChunk {
+    /// The type of the chunk.
+    #[serde(rename = "type")]
+    pub _type: StreamChunkType,
+    /// The final `stop_reason` and `stop_sequence`.
+    pub delta: StreamStop,
+    /// Cumulative usage for this delta.
+    pub usage: DeltaUsage,
+}
+
+/// The `stop_reason`/`stop_sequence` carried by a [`MessageDeltaChunk`].
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct StreamStop {
+    /// The reason that we stopped, if any yet.
+    pub stop_reason: Option<StopReason>,
+    /// Which custom stop sequence was generated, if any.
+    pub stop_sequence: Option<StopSequence>,
+}
+
+/// Usage carried by a [`MessageDeltaChunk`].
+///
+/// Note that `output_tokens` here is cumulative for the message so far, not
+/// a per-delta increment.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct DeltaUsage {
+    /// The cumulative number of output tokens generated so far.
+    pub output_tokens: u32,
+}
+
+/// The final event of a stream.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageStopChunk {
+    /// The type of the chunk.
+    #[serde(rename = "type")]
+    pub _type: StreamChunkType,
+}
+
+/// A keep-alive event that carries no data.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct PingChunk {
+    /// The type of the chunk.
+    #[serde(rename = "type")]
+    pub _type: StreamChunkType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{ClaudeModel, MessageObjectType, Role};
+
+    #[test]
+    fn message_start_chunk_deserialize() {
+        let chunk: StreamChunk = serde_json::from_str(
+            "{\"type\":\"message_start\",\"message\":{\"id\":\"id\",\"type\":\"message\",\"role\":\"assistant\",\"content\":[],\"model\":\"claude-3-sonnet-20240229\",\"stop_reason\":null,\"stop_sequence\":null,\"usage\":{\"input_tokens\":1,\"output_tokens\":0}}}"
+        )
+        .unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::MessageStart(MessageStartChunk {
+                _type: StreamChunkType::MessageStart,
+                message: MessagesResponseBody {
+                    id: "id".to_string(),
+                    _type: MessageObjectType::Message,
+                    role: Role::Assistant,
+                    content: Vec::<ContentBlock>::new().into(),
+                    model: ClaudeModel::Claude3Sonnet20240229,
+                    stop_reason: None,
+                    stop_sequence: None,
+                    usage: crate::messages::Usage {
+                        input_tokens: 1,
+                        output_tokens: 0,
+                    },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn content_block_delta_chunk_text_deserialize() {
+        let chunk: StreamChunk = serde_json::from_str(
+            "{\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hello\"}}"
+        )
+        .unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk {
+                _type: StreamChunkType::ContentBlockDelta,
+                index: 0,
+                delta: ContentBlockDelta::TextDelta(
+                    TextDeltaContentBlock {
+                        _type: ContentType::TextDelta,
+                        text: "Hello".to_string(),
+                    }
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn content_block_delta_chunk_input_json_deserialize() {
+        let chunk: StreamChunk = serde_json::from_str(
+            "{\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"location\\\":\"}}"
+        )
+        .unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk {
+                _type: StreamChunkType::ContentBlockDelta,
+                index: 1,
+                delta: ContentBlockDelta::InputJsonDelta(
+                    InputJsonDeltaContentBlock {
+                        _type: ContentType::InputJsonDelta,
+                        partial_json: "{\"location\":".to_string(),
+                    }
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn message_delta_chunk_deserialize() {
+        let chunk: StreamChunk = serde_json::from_str(
+            "{\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"end_turn\",\"stop_sequence\":null},\"usage\":{\"output_tokens\":15}}"
+        )
+        .unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::MessageDelta(MessageDeltaChunk {
+                _type: StreamChunkType::MessageDelta,
+                delta: StreamStop {
+                    stop_reason: Some(StopReason::EndTurn),
+                    stop_sequence: None,
+                },
+                usage: DeltaUsage { output_tokens: 15 },
+            })
+        );
+    }
+
+    #[test]
+    fn message_stop_chunk_deserialize() {
+        let chunk: StreamChunk =
+            serde_json::from_str("{\"type\":\"message_stop\"}").unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::MessageStop(MessageStopChunk {
+                _type: StreamChunkType::MessageStop,
+            })
+        );
+    }
+
+    #[test]
+    fn ping_chunk_deserialize() {
+        let chunk: StreamChunk =
+            serde_json::from_str("{\"type\":\"ping\"}").unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::Ping(PingChunk {
+                _type: StreamChunkType::Ping,
+            })
+        );
+    }
+
+    #[test]
+    fn stream_chunk_type_display() {
+        assert_eq!(
+            StreamChunkType::MessageStart.to_string(),
+            "message_start"
+        );
+    }
+}
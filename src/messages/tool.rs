@@ -0,0 +1,166 @@
+use crate::macros::impl_display_for_serialize;
+
+/// A tool that Claude may call while generating a response.
+///
+/// See also [the tool use guide](https://docs.anthropic.com/claude/docs/tool-use).
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct Tool {
+    /// The name of the tool.
+    pub name: String,
+    /// A description of what the tool does, used by Claude to decide when
+    /// and how to call it.
+    pub description: String,
+    /// A JSON Schema object describing the shape of the tool's input.
+    pub input_schema: serde_json::Value,
+}
+
+impl_display_for_serialize!(Tool);
+
+impl Tool {
+    /// Creates a new tool with the given name, description and JSON Schema
+    /// input schema.
+    pub fn new<S>(
+        name: S,
+        description: S,
+        input_schema: serde_json::Value,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            input_schema,
+        }
+    }
+
+    /// Creates a new tool whose `input_schema` is derived from a Rust type
+    /// that implements [`schemars::JsonSchema`].
+    ///
+    /// This removes the need to hand-write the JSON Schema for a tool that
+    /// is backed by a plain Rust struct; pair it with
+    /// [`ToolUseContentBlock::input_as`](crate::messages::ToolUseContentBlock::input_as)
+    /// to deserialize the arguments Claude sends back into the same type.
+    #[cfg(feature = "schemars")]
+    pub fn from_type<S, T>(
+        name: S,
+        description: S,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: schemars::JsonSchema,
+    {
+        let schema = schemars::schema_for!(T);
+        Self {
+            name: name.into(),
+            description: description.into(),
+            input_schema: serde_json::to_value(schema.schema)
+                .expect("a JSON Schema always serializes to JSON"),
+        }
+    }
+}
+
+/// How Claude should decide whether to call a tool.
+#[derive(
+    Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ToolChoice {
+    /// Claude decides by itself whether to use a tool and which one to use.
+    Auto,
+    /// Claude must use one of the provided tools.
+    Any,
+    /// Claude must use the named tool.
+    Tool {
+        /// The name of the tool Claude must use.
+        name: String,
+    },
+}
+
+impl_display_for_serialize!(ToolChoice);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let tool = Tool::new(
+            "get_weather",
+            "Get the current weather in a location",
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "location": { "type": "string" }
+                },
+                "required": ["location"]
+            }),
+        );
+        assert_eq!(tool.name, "get_weather");
+    }
+
+    #[test]
+    fn serialize() {
+        let tool = Tool::new(
+            "get_weather",
+            "Get the current weather in a location",
+            serde_json::json!({ "type": "object" }),
+        );
+        assert_eq!(
+            serde_json::to_string(&tool).unwrap(),
+            "{\"name\":\"get_weather\",\"description\":\"Get the current weather in a location\",\"input_schema\":{\"type\":\"object\"}}"
+        );
+    }
+
+    #[test]
+    fn tool_choice_serialize_auto() {
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::Auto).unwrap(),
+            "{\"type\":\"auto\"}"
+        );
+    }
+
+    #[test]
+    fn tool_choice_serialize_any() {
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::Any).unwrap(),
+            "{\"type\":\"any\"}"
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[derive(Debug, PartialEq, serde::Deserialize, schemars::JsonSchema)]
+    struct GetWeatherInput {
+        location: String,
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn from_type() {
+        let tool = Tool::from_type::<&str, GetWeatherInput>(
+            "get_weather",
+            "Get the current weather in a location",
+        );
+        assert_eq!(tool.name, "get_weather");
+        assert_eq!(
+            tool.input_schema
+                .get("properties")
+                .and_then(|properties| properties.get("location"))
+                .is_some(),
+            true
+        );
+    }
+
+    #[test]
+    fn tool_choice_serialize_tool() {
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::Tool {
+                name: "get_weather".to_string()
+            })
+            .unwrap(),
+            "{\"type\":\"tool\",\"name\":\"get_weather\"}"
+        );
+    }
+}